@@ -0,0 +1,116 @@
+//! Elliptic Curve Diffie-Hellman (ECDH) key agreement over secp256k1.
+//!
+//! This module builds directly on the existing [`AffinePoint`],
+//! [`ProjectivePoint`], [`NonZeroScalar`], and [`EncodedPoint`] types, so it
+//! is available wherever those are (i.e. it does not depend on the `ecdsa`
+//! feature).
+//!
+//! Like `schnorr`, this module needs `pub mod ecdh;` plus an `ecdh = []`
+//! Cargo feature added in `lib.rs`/`Cargo.toml`, neither of which exists in
+//! this checkout; see the note at the top of `schnorr.rs` for why
+//! reconstructing those files is out of scope here.
+
+use crate::{AffinePoint, ElementBytes, EncodedPoint, NonZeroScalar, ProjectivePoint};
+use elliptic_curve::{
+    rand_core::{CryptoRng, RngCore},
+    sec1::FromEncodedPoint,
+    Generate,
+};
+use zeroize::Zeroize;
+
+/// An ephemeral Diffie-Hellman secret, wrapping a freshly-generated
+/// [`NonZeroScalar`] that is zeroized on drop.
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub struct EphemeralSecret {
+    /// Ephemeral secret scalar
+    scalar: NonZeroScalar,
+}
+
+impl EphemeralSecret {
+    /// Generate a new ephemeral secret using the provided CSRNG.
+    pub fn generate(rng: impl CryptoRng + RngCore) -> Self {
+        Self {
+            scalar: NonZeroScalar::generate(rng),
+        }
+    }
+
+    /// Get the public key corresponding to this ephemeral secret, to be
+    /// sent to the other party in the key agreement.
+    pub fn public_key(&self) -> EncodedPoint {
+        let affine_point = (ProjectivePoint::generator() * self.scalar.as_ref())
+            .to_affine()
+            .unwrap();
+
+        EncodedPoint::from(&affine_point)
+    }
+}
+
+impl Drop for EphemeralSecret {
+    fn drop(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+/// Compute a Diffie-Hellman shared secret from an ephemeral secret and the
+/// other party's public point.
+pub fn diffie_hellman(
+    secret: &EphemeralSecret,
+    public_key: &EncodedPoint,
+) -> Result<SharedSecret, elliptic_curve::Error> {
+    let affine_point = AffinePoint::from_encoded_point(public_key);
+
+    if affine_point.is_none().into() {
+        return Err(elliptic_curve::Error);
+    }
+
+    let shared_point = ProjectivePoint::from(affine_point.unwrap()) * secret.scalar.as_ref();
+    let shared_affine = shared_point.to_affine().unwrap();
+
+    Ok(SharedSecret {
+        secret_bytes: shared_affine.x.to_bytes(),
+    })
+}
+
+/// Shared secret value computed via ECDH key agreement.
+///
+/// Exposes the x-coordinate of the resulting elliptic curve point as opaque
+/// bytes; callers should pass this through a KDF rather than using it
+/// directly as key material.
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+pub struct SharedSecret {
+    /// Shared secret value: x-coordinate of the computed point
+    secret_bytes: ElementBytes,
+}
+
+impl SharedSecret {
+    /// Borrow the shared secret value as bytes.
+    pub fn as_bytes(&self) -> &ElementBytes {
+        &self.secret_bytes
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.secret_bytes.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, EphemeralSecret};
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn diffie_hellman_agrees_both_directions() {
+        let alice_secret = EphemeralSecret::generate(&mut OsRng);
+        let alice_public = alice_secret.public_key();
+
+        let bob_secret = EphemeralSecret::generate(&mut OsRng);
+        let bob_public = bob_secret.public_key();
+
+        let alice_shared = diffie_hellman(&alice_secret, &bob_public).unwrap();
+        let bob_shared = diffie_hellman(&bob_secret, &alice_public).unwrap();
+
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+    }
+}
@@ -0,0 +1,114 @@
+//! Low-level ECDSA/secp256k1 primitives.
+//!
+//! These free functions implement the raw nonce-based signing and
+//! verification math, decoupled from the [`ecdsa_core::hazmat`] traits that
+//! normally gate access to it. They exist for advanced use cases (threshold
+//! signing, deterministic-`k` experiments, remote-signer backends) that need
+//! the primitive directly rather than going through [`super::Signer`] or
+//! [`super::Verifier`]; those types (and the recoverable-signature variants)
+//! are implemented in terms of these functions rather than duplicating the
+//! underlying arithmetic.
+//!
+//! `signer.rs` and `verifier.rs` reach this module via `use super::hazmat`,
+//! which requires `mod hazmat;` in `ecdsa/mod.rs`. That file (along with
+//! `lib.rs` and `Cargo.toml`) isn't part of this checkout — it predates this
+//! series, and the baseline `signer.rs`/`verifier.rs` already depended on
+//! crate-root items (`Secp256k1`, `Scalar`, `SecretKey`,
+//! `test_vectors::ecdsa::ECDSA_TEST_VECTORS`) that only that file defines.
+//! Wiring this module in for real means reconstructing that file along with
+//! the curve-arithmetic types it provides, which is out of scope here; the
+//! declaration this module needs is exactly `mod hazmat;`.
+
+use super::{recoverable, Error, Signature};
+use crate::{AffinePoint, ProjectivePoint, Scalar};
+use core::borrow::Borrow;
+use elliptic_curve::ops::Invert;
+
+/// Sign a prehashed message scalar `z` with secret scalar `d` and ephemeral
+/// scalar `k`, returning the resulting [`Signature`] along with a
+/// [`recoverable::Id`] that records the sign bit of `R` and whether `s` was
+/// normalized to low-S form.
+#[allow(non_snake_case, clippy::many_single_char_names)]
+pub fn sign_prehashed<K>(
+    k: &K,
+    d: &Scalar,
+    z: &Scalar,
+) -> Result<(Signature, recoverable::Id), Error>
+where
+    K: Borrow<Scalar> + Invert<Output = Scalar>,
+{
+    let k_inverse = k.invert();
+    let k = k.borrow();
+
+    if k_inverse.is_none().into() || k.is_zero().into() {
+        return Err(Error::new());
+    }
+
+    let k_inverse = k_inverse.unwrap();
+
+    // Compute 𝐑 = 𝑘×𝑮
+    let R = (ProjectivePoint::generator() * k).to_affine().unwrap();
+
+    // Lift x-coordinate of 𝐑 (element of base field) into a serialized big
+    // integer, then reduce it into an element of the scalar field
+    let r = Scalar::from_bytes_reduced(&R.x.to_bytes());
+
+    // Compute `s` as a signature over `r` and `z`.
+    let s = k_inverse * &(z + &(r * d));
+
+    if s.is_zero().into() {
+        return Err(Error::new());
+    }
+
+    let mut signature = Signature::from_scalars(&r.into(), &s.into());
+    let is_r_odd = bool::from(R.y.normalize().is_odd());
+    let is_s_high = signature.normalize_s()?;
+
+    // Bit 1 of the recovery ID records whether `R.x`, as a field element,
+    // was greater than or equal to the group order `n` (and was thus
+    // reduced when computing `r`). `r`, like the rest of the signature, is
+    // public, so this comparison need not run in constant time.
+    let is_r_reduced = R.x.to_bytes() != crate::ElementBytes::from(r);
+    let recovery_id = recoverable::Id((is_r_odd ^ is_s_high) as u8 | ((is_r_reduced as u8) << 1));
+
+    Ok((signature, recovery_id))
+}
+
+/// Verify a signature `(r, s)` over prehashed message scalar `z` against
+/// public point `public_key`.
+pub fn verify_prehashed(
+    public_key: &AffinePoint,
+    z: &Scalar,
+    signature: &Signature,
+) -> Result<(), Error> {
+    let maybe_r = crate::NonZeroScalar::from_bytes(signature.r());
+    let maybe_s = crate::NonZeroScalar::from_bytes(signature.s());
+
+    // TODO(tarcieri): replace with into conversion when available (see subtle#73)
+    let (r, s) = if maybe_r.is_some().into() && maybe_s.is_some().into() {
+        (maybe_r.unwrap(), maybe_s.unwrap())
+    } else {
+        return Err(Error::new());
+    };
+
+    // Ensure signature is "low S" normalized ala BIP 0062
+    if s.is_high().into() {
+        return Err(Error::new());
+    }
+
+    let s_inv = s.invert().unwrap();
+    let u1 = z * &s_inv;
+    let u2 = *r * &s_inv;
+
+    let x = ((&ProjectivePoint::generator() * &u1)
+        + &(ProjectivePoint::from(*public_key) * &u2))
+        .to_affine()
+        .unwrap()
+        .x;
+
+    if Scalar::from_bytes_reduced(&x.to_bytes()).eq(&r) {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
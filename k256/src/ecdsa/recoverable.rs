@@ -41,10 +41,10 @@ use ecdsa_core::{signature::Signature as _, Error};
 use crate::{
     ecdsa::VerifyKey,
     elliptic_curve::{
-        consts::U32, ops::Invert, subtle::Choice, weierstrass::point::Decompress, Digest,
-        FromBytes, FromDigest,
+        consts::U32, ops::Invert, sec1::FromEncodedPoint, subtle::Choice,
+        weierstrass::point::Decompress, Digest, FromBytes, FromDigest,
     },
-    AffinePoint, NonZeroScalar, ProjectivePoint, Scalar,
+    AffinePoint, ElementBytes, NonZeroScalar, ProjectivePoint, Scalar,
 };
 
 #[cfg(any(feature = "ecdsa", docsrs))]
@@ -104,7 +104,7 @@ impl Signature {
         let mut signature = *signature;
         signature.normalize_s()?;
 
-        for recovery_id in 0..=1 {
+        for recovery_id in 0..=3 {
             if let Ok(recoverable_signature) = Signature::new(&signature, Id(recovery_id)) {
                 if let Ok(recovered_key) = recoverable_signature.recover_verify_key(msg) {
                     if public_key == &EncodedPoint::from(&recovered_key) {
@@ -129,15 +129,57 @@ impl Signature {
     /// [`EncodedPoint`] from the provided precomputed [`Digest`].
     #[cfg(feature = "ecdsa")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
-    #[allow(non_snake_case, clippy::many_single_char_names)]
     pub fn recover_verify_key_from_digest<D>(&self, msg_prehash: D) -> Result<VerifyKey, Error>
+    where
+        D: Digest<OutputSize = U32>,
+    {
+        self.recover_affine_point_from_digest(msg_prehash)
+            .map(|pk| VerifyKey::from(&pk))
+    }
+
+    /// Recover the 20-byte Ethereum address of the signer of the given
+    /// message, e.g. for verifying `ecrecover`-style signatures.
+    #[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")), doc(cfg(feature = "keccak256")))]
+    pub fn recover_ethereum_address(&self, msg: &[u8]) -> Result<[u8; 20], Error> {
+        self.recover_ethereum_address_from_digest(Keccak256::new().chain(msg))
+    }
+
+    /// Recover the 20-byte Ethereum address of the signer from the provided
+    /// precomputed [`Digest`].
+    #[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")), doc(cfg(feature = "keccak256")))]
+    pub fn recover_ethereum_address_from_digest<D>(&self, msg_prehash: D) -> Result<[u8; 20], Error>
+    where
+        D: Digest<OutputSize = U32>,
+    {
+        let pk = self.recover_affine_point_from_digest(msg_prehash)?;
+        Ok(affine_point_to_ethereum_address(&pk))
+    }
+
+    /// Recover the public key used to create this signature, as a raw
+    /// [`AffinePoint`], from the provided precomputed [`Digest`].
+    #[cfg(feature = "ecdsa")]
+    #[allow(non_snake_case, clippy::many_single_char_names)]
+    fn recover_affine_point_from_digest<D>(&self, msg_prehash: D) -> Result<AffinePoint, Error>
     where
         D: Digest<OutputSize = U32>,
     {
         let r = self.r();
         let s = self.s();
         let z = Scalar::from_digest(msg_prehash);
-        let R = AffinePoint::decompress(&r.to_bytes(), self.recovery_id().is_y_odd());
+
+        // If the recovery ID indicates `r` was reduced modulo the group
+        // order during signing (i.e. the x-coordinate of `R` exceeded the
+        // group order `n`), reconstruct the original field element as
+        // `r + n` before attempting to decompress it.
+        let r_bytes = if bool::from(self.recovery_id().is_x_reduced()) {
+            add_scalar_order(&r.to_bytes())
+        } else {
+            r.to_bytes()
+        };
+
+        let R = AffinePoint::decompress(&r_bytes, self.recovery_id().is_y_odd());
 
         // TODO(tarcieri): replace with into conversion when available (see subtle#73)
         if R.is_some().into() {
@@ -148,7 +190,7 @@ impl Signature {
             let pk = ((&ProjectivePoint::generator() * &u1) + &(R * &u2)).to_affine();
 
             if pk.is_some().into() {
-                return Ok(VerifyKey::from(&pk.unwrap()));
+                return Ok(pk.unwrap());
             }
         }
 
@@ -235,17 +277,73 @@ impl ecdsa_core::signature::PrehashSignature for Signature {
     type Digest = Keccak256;
 }
 
-/// Identifier used to compute a [`EncodedPoint`] from a [`Signature`].
-///
-/// In practice these values are always either `0` or `1`, and indicate
-/// whether or not the y-coordinate of the original [`EncodedPoint`] is odd.
+/// The secp256k1 group order `n`, encoded as big endian bytes.
 ///
-/// While values `2` and `3` are also defined to capture whether `r`
-/// overflowed the curve's order, this crate does *not* support them.
+/// Used to restore the original field element of `R.x` when the recovery
+/// ID indicates it was reduced modulo `n` (i.e. bit 1 of the `Id` is set).
+#[cfg(feature = "ecdsa")]
+const ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Derive the 20-byte Ethereum address of a public key: the low 20 bytes of
+/// the Keccak256 hash of its untagged, uncompressed `X || Y` encoding.
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+fn affine_point_to_ethereum_address(pk: &AffinePoint) -> [u8; 20] {
+    let mut untagged = [0u8; 64];
+    untagged[..32].copy_from_slice(&pk.x.to_bytes());
+    untagged[32..].copy_from_slice(&pk.y.normalize().to_bytes());
+
+    let digest = Keccak256::digest(&untagged);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&digest[12..]);
+    address
+}
+
+/// Extension trait adding Ethereum address derivation to [`VerifyKey`],
+/// independent of whether it was parsed directly or recovered from a
+/// [`Signature`].
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")), doc(cfg(feature = "keccak256")))]
+pub trait ToEthereumAddress {
+    /// Derive the 20-byte Ethereum address of this public key: the low 20
+    /// bytes of the Keccak256 hash of its uncompressed `X || Y` encoding.
+    fn to_ethereum_address(&self) -> [u8; 20];
+}
+
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+impl ToEthereumAddress for VerifyKey {
+    fn to_ethereum_address(&self) -> [u8; 20] {
+        let affine_point = AffinePoint::from_encoded_point(&EncodedPoint::from(self)).unwrap();
+        affine_point_to_ethereum_address(&affine_point)
+    }
+}
+
+/// Add the group order `n` to a 32-byte big endian field element, as
+/// required to restore `R.x` when it was reduced during signing.
+#[cfg(feature = "ecdsa")]
+fn add_scalar_order(bytes: &ElementBytes) -> ElementBytes {
+    let mut out = ElementBytes::default();
+    let mut carry = 0u16;
+
+    for i in (0..32).rev() {
+        let sum = u16::from(bytes[i]) + u16::from(ORDER[i]) + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+
+    out
+}
+
+/// Identifier used to compute a [`EncodedPoint`] from a [`Signature`].
 ///
-/// There is a vanishingly small chance of these values occurring outside
-/// of contrived examples, so for simplicity's sake handling these values
-/// is unsupported and will return an `Error` when parsing the `Id`.
+/// In practice these values are almost always either `0` or `1`, which
+/// indicate whether or not the y-coordinate of the original [`EncodedPoint`]
+/// is odd. Values `2` and `3` are also defined to capture whether `r`
+/// overflowed the curve's order when it was computed (an event with only a
+/// vanishingly small chance of occurring), and are supported here for
+/// interoperability with other secp256k1 recovery implementations.
 #[derive(Copy, Clone, Debug)]
 pub struct Id(pub(super) u8);
 
@@ -253,7 +351,7 @@ impl Id {
     /// Create a new [`Id`] from the given byte value
     pub fn new(byte: u8) -> Result<Self, Error> {
         match byte {
-            0 | 1 => Ok(Self(byte)),
+            0..=3 => Ok(Self(byte)),
             _ => Err(Error::new()),
         }
     }
@@ -261,7 +359,13 @@ impl Id {
     /// Is `y` odd?
     #[cfg(feature = "ecdsa")]
     fn is_y_odd(self) -> Choice {
-        self.0.into()
+        (self.0 & 1).into()
+    }
+
+    /// Was the x-coordinate of `R` reduced when computing `r`?
+    #[cfg(feature = "ecdsa")]
+    pub fn is_x_reduced(self) -> Choice {
+        ((self.0 >> 1) & 1).into()
     }
 }
 
@@ -287,6 +391,9 @@ mod tests {
     use hex_literal::hex;
     use sha2::{Digest, Sha256};
 
+    #[cfg(feature = "keccak256")]
+    use super::ToEthereumAddress;
+
     /// Signature recovery test vectors
     struct TestVector {
         pk: [u8; 33],
@@ -324,4 +431,82 @@ mod tests {
             assert_eq!(&vector.pk[..], EncodedPoint::from(&pk).as_bytes());
         }
     }
+
+    #[test]
+    #[cfg(feature = "keccak256")]
+    fn verify_key_to_ethereum_address_matches_recovery() {
+        for vector in VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let prehash = Sha256::new().chain(vector.msg);
+            let pk = sig.recover_verify_key_from_digest(prehash.clone()).unwrap();
+
+            assert_eq!(
+                pk.to_ethereum_address(),
+                sig.recover_ethereum_address_from_digest(prehash).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn recovery_through_x_reduced_branch() {
+        // A synthetic vector whose `R.x` exceeds the group order `n` by `2`,
+        // exercising the `is_x_reduced` branch of
+        // `recover_affine_point_from_digest` (i.e. `add_scalar_order`
+        // restoring the original field element before
+        // `AffinePoint::decompress`). A genuine signature with `R.x >= n`
+        // has a ~2^-128 chance of occurring, so rather than search for one
+        // via real signing, this vector is derived directly from the ECDSA
+        // recovery equation `pk = r^-1 * (s*R - z*G)` for an `R` with
+        // x-coordinate `n + 2` (independently verified to be on-curve) and
+        // `s = 1`.
+        let sig_bytes = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000002
+             000000000000000000000000000000000000000000000000000000000000000103"
+        );
+        let sig = Signature::try_from(&sig_bytes[..]).unwrap();
+        assert!(bool::from(sig.recovery_id().is_x_reduced()));
+
+        let msg: &[u8] = b"k256 recoverable overflow test vector";
+        let prehash = Sha256::new().chain(msg);
+        let recovered = sig.recover_verify_key_from_digest(prehash).unwrap();
+
+        let expected =
+            hex!("021d89c2f386976c79a609f987e60eefa6b952cc48dd0c45c56b88613c1f444a14");
+        assert_eq!(EncodedPoint::from(&recovered).as_bytes(), &expected[..]);
+    }
+
+    #[test]
+    fn recovery_id_accepts_overflow_values() {
+        for byte in 0..=3 {
+            assert!(super::Id::new(byte).is_ok());
+        }
+
+        assert!(super::Id::new(4).is_err());
+    }
+
+    #[test]
+    fn recovery_id_x_reduced_bit() {
+        assert!(!bool::from(super::Id::new(0).unwrap().is_x_reduced()));
+        assert!(!bool::from(super::Id::new(1).unwrap().is_x_reduced()));
+        assert!(bool::from(super::Id::new(2).unwrap().is_x_reduced()));
+        assert!(bool::from(super::Id::new(3).unwrap().is_x_reduced()));
+    }
+
+    #[test]
+    fn add_scalar_order_restores_reduced_x_coordinate() {
+        // `r` here is an arbitrarily chosen small scalar standing in for
+        // `R.x mod n`; adding the group order back should yield the
+        // original (larger) field element `R.x`.
+        let mut r = [0u8; 32];
+        r[31] = 7;
+
+        let restored = super::add_scalar_order(&r.into());
+
+        // secp256k1 group order `n`
+        let n = hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+        let mut expected = n;
+        expected[31] = expected[31].wrapping_add(7);
+
+        assert_eq!(&restored[..], &expected[..]);
+    }
 }
@@ -1,9 +1,10 @@
 //! ECDSA verifier
 
-use super::{recoverable, Error, Signature};
-use crate::{AffinePoint, EncodedPoint, NonZeroScalar, ProjectivePoint, Scalar, Secp256k1};
+use super::{hazmat, recoverable, Error, Signature};
+use crate::{AffinePoint, EncodedPoint, Scalar, Secp256k1};
+use core::convert::TryFrom;
 use ecdsa_core::{hazmat::VerifyPrimitive, signature};
-use elliptic_curve::{consts::U32, ops::Invert, FromBytes};
+use elliptic_curve::consts::U32;
 use signature::{digest::Digest, DigestVerifier, PrehashSignature};
 
 /// ECDSA/secp256k1 verifier
@@ -53,35 +54,24 @@ where
 
 impl VerifyPrimitive<Secp256k1> for AffinePoint {
     fn verify_prehashed(&self, z: &Scalar, signature: &Signature) -> Result<(), Error> {
-        let maybe_r = NonZeroScalar::from_bytes(signature.r());
-        let maybe_s = NonZeroScalar::from_bytes(signature.s());
-
-        // TODO(tarcieri): replace with into conversion when available (see subtle#73)
-        let (r, s) = if maybe_r.is_some().into() && maybe_s.is_some().into() {
-            (maybe_r.unwrap(), maybe_s.unwrap())
-        } else {
-            return Err(Error::new());
-        };
-
-        // Ensure signature is "low S" normalized ala BIP 0062
-        if s.is_high().into() {
-            return Err(Error::new());
-        }
-
-        let s_inv = s.invert().unwrap();
-        let u1 = z * &s_inv;
-        let u2 = *r * &s_inv;
+        hazmat::verify_prehashed(self, z, signature)
+    }
+}
 
-        let x = ((&ProjectivePoint::generator() * &u1) + &(ProjectivePoint::from(*self) * &u2))
-            .to_affine()
-            .unwrap()
-            .x;
+// Note: only the SPKI encoding (the public-key half of PKCS#8) is
+// implemented below. A SEC1 `ECPrivateKey` document (RFC 5915) carries a
+// *private* key, with the public key only as an optional attribute, so it
+// is not a natural fit for `Verifier`; parsing a raw SEC1 document's public
+// key would instead need the `sec1` crate's `ECPoint`/`EncodedPoint`
+// support directly, which `Verifier::new` already accepts.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl pkcs8::FromPublicKey for Verifier {
+    fn from_spki(spki: pkcs8::SubjectPublicKeyInfo<'_>) -> pkcs8::Result<Self> {
+        let public_key = EncodedPoint::try_from(spki.subject_public_key)
+            .map_err(|_| pkcs8::Error::KeyMalformed)?;
 
-        if Scalar::from_bytes_reduced(&x.to_bytes()).eq(&r) {
-            Ok(())
-        } else {
-            Err(Error::new())
-        }
+        Self::new(&public_key).map_err(|_| pkcs8::Error::KeyMalformed)
     }
 }
 
@@ -90,3 +80,35 @@ mod tests {
     use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, Secp256k1};
     ecdsa_core::new_verification_test!(Secp256k1, ECDSA_TEST_VECTORS);
 }
+
+#[cfg(all(test, feature = "pkcs8", feature = "sha256"))]
+mod pkcs8_tests {
+    use super::{Signature, Verifier};
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+    use pkcs8::FromPublicKey;
+    use signature::Verifier as _;
+
+    /// SPKI `SubjectPublicKeyInfo` for the same secp256k1 key used by
+    /// `signer::pkcs8_tests::PKCS8_DER`, generated with `cryptography`'s
+    /// `Encoding.DER`/`PublicFormat.SubjectPublicKeyInfo` serializer.
+    const SPKI_DER: &[u8] = &hex!(
+        "3056301006072a8648ce3d020106052b8104000a03420004df6ae0e985b655fbc6
+         bc7ed04b1f03098b13eb7d76a6ba4144987a3555d1eba06f297cf691be4ac63b9c
+         79f6d115ad9e7492b27018202e3dfbe659ad89adf691"
+    );
+
+    #[test]
+    fn spki_parse_and_verify() {
+        let verifier = Verifier::from_public_key_der(SPKI_DER).unwrap();
+
+        let msg = b"k256 pkcs8 round trip test vector";
+        let sig_bytes = hex!(
+            "7784b02f813c36a1965f68ac17981fe44137b1c11d409b9555da71497a02f7f
+             52713ec410d5579ff4475f49d4cf10f7432115ce02197931d9f55b34b46d7a60c"
+        );
+        let signature = Signature::try_from(&sig_bytes[..]).unwrap();
+
+        assert!(verifier.verify(msg, &signature).is_ok());
+    }
+}
@@ -0,0 +1,427 @@
+//! Taproot Schnorr signatures ([BIP-340]) over secp256k1.
+//!
+//! These differ from "regular" ECDSA/secp256k1 signatures in that public keys
+//! are encoded as the 32-byte x-coordinate of a point with an *implicit*
+//! even y-coordinate, and the scalar used to produce that point (or a nonce
+//! commitment) is negated whenever necessary to make that so.
+//!
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//!
+//! This module is only reachable once `lib.rs` adds `pub mod schnorr;`
+//! behind a `schnorr` Cargo feature. Neither file is part of this checkout
+//! (it has no `Cargo.toml` anywhere in its history, including baseline), and
+//! `lib.rs` also defines the curve-arithmetic types (`Secp256k1`, `Scalar`,
+//! `AffinePoint`, ...) this module imports from `crate::`, so reconstructing
+//! it is out of scope for this series. The declarations needed once that
+//! file exists are `pub mod schnorr;` plus a `schnorr = []` feature entry.
+
+use crate::{AffinePoint, ElementBytes, NonZeroScalar, ProjectivePoint, Scalar, SecretKey};
+use core::convert::TryInto;
+use ecdsa_core::Error;
+use elliptic_curve::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    weierstrass::point::Decompress,
+    FromBytes,
+};
+use sha2::{digest::Digest, Sha256};
+
+/// Size of a BIP-340 Schnorr signature in bytes.
+pub const SIZE: usize = 64;
+
+/// x-only public key encoding: the 32-byte x-coordinate of a point whose
+/// y-coordinate is implicitly even.
+type XOnlyBytes = ElementBytes;
+
+/// Compute a BIP-340 tagged hash: `SHA256(SHA256(tag) || SHA256(tag) || msg)`.
+fn tagged_hash(tag: &[u8], msg: &[&[u8]]) -> ElementBytes {
+    let tag_hash = Sha256::digest(tag);
+
+    let mut digest = Sha256::new();
+    digest.update(&tag_hash);
+    digest.update(&tag_hash);
+
+    for part in msg {
+        digest.update(part);
+    }
+
+    digest.finalize()
+}
+
+/// Negate `scalar` if `point`'s y-coordinate is odd, returning the
+/// (possibly negated) scalar along with the x-only encoding of `point`.
+#[allow(non_snake_case)]
+fn lift_x(scalar: Scalar, point: AffinePoint) -> (Scalar, XOnlyBytes) {
+    let y_is_odd = point.y.normalize().is_odd();
+    let negated = Scalar::conditional_select(&scalar, &-scalar, y_is_odd);
+    (negated, point.x.to_bytes())
+}
+
+/// BIP-340 Schnorr signing key.
+#[cfg_attr(docsrs, doc(cfg(feature = "schnorr")))]
+pub struct SigningKey {
+    /// Secret scalar, negated at construction time if necessary so that the
+    /// corresponding public point has an even y-coordinate.
+    secret_scalar: Scalar,
+
+    /// x-only encoding of the public key.
+    public_key_bytes: XOnlyBytes,
+}
+
+impl SigningKey {
+    /// Create a signing key from a [`SecretKey`].
+    pub fn new(secret_key: &SecretKey) -> Result<Self, Error> {
+        let scalar = NonZeroScalar::from_bytes(secret_key.as_bytes());
+
+        if scalar.is_none().into() {
+            return Err(Error::new());
+        }
+
+        let scalar = *scalar.unwrap().as_ref();
+        let public_point = (ProjectivePoint::generator() * &scalar).to_affine().unwrap();
+        let (secret_scalar, public_key_bytes) = lift_x(scalar, public_point);
+
+        Ok(Self {
+            secret_scalar,
+            public_key_bytes,
+        })
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this signing key.
+    pub fn verifying_key(&self) -> VerifyingKey {
+        VerifyingKey {
+            public_key_bytes: self.public_key_bytes,
+        }
+    }
+
+    /// Sign the given 32-byte prehashed message, using `aux_rand` as
+    /// auxiliary randomness per BIP-340.
+    #[allow(non_snake_case)]
+    pub fn try_sign_prehashed(
+        &self,
+        msg: &[u8; 32],
+        aux_rand: &[u8; 32],
+    ) -> Result<Signature, Error> {
+        let d = self.secret_scalar;
+        let P_x = &self.public_key_bytes;
+
+        let d_bytes: ElementBytes = d.into();
+        let aux_hash = tagged_hash(b"BIP0340/aux", &[aux_rand]);
+        let mut t = ElementBytes::default();
+
+        for i in 0..32 {
+            t[i] = d_bytes[i] ^ aux_hash[i];
+        }
+
+        let rand = tagged_hash(b"BIP0340/nonce", &[&t, P_x, msg]);
+        let k = Scalar::from_bytes_reduced(&rand);
+
+        if k.is_zero().into() {
+            return Err(Error::new());
+        }
+
+        let R = (ProjectivePoint::generator() * &k).to_affine().unwrap();
+        let (k, R_x) = lift_x(k, R);
+
+        let e_hash = tagged_hash(b"BIP0340/challenge", &[&R_x, P_x, msg]);
+        let e = Scalar::from_bytes_reduced(&e_hash);
+        let s = k + &(e * &d);
+
+        let mut bytes = [0u8; SIZE];
+        bytes[..32].copy_from_slice(&R_x);
+        bytes[32..].copy_from_slice(&ElementBytes::from(s));
+        Ok(Signature { bytes })
+    }
+}
+
+/// BIP-340 Schnorr verifying (x-only public) key.
+#[cfg_attr(docsrs, doc(cfg(feature = "schnorr")))]
+#[derive(Copy, Clone)]
+pub struct VerifyingKey {
+    /// x-only encoding of the public key (implicit even y-coordinate).
+    public_key_bytes: XOnlyBytes,
+}
+
+impl VerifyingKey {
+    /// Parse a [`VerifyingKey`] from its 32-byte x-only encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let public_key_bytes: XOnlyBytes =
+            bytes.try_into().map_err(|_| Error::new())?;
+
+        // Ensure the x-coordinate actually lifts to a valid curve point.
+        if AffinePoint::decompress(&public_key_bytes, Choice::from(0))
+            .is_none()
+            .into()
+        {
+            return Err(Error::new());
+        }
+
+        Ok(Self { public_key_bytes })
+    }
+
+    /// Borrow the x-only encoding of this public key.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.public_key_bytes
+    }
+
+    /// Verify a BIP-340 Schnorr `signature` over the given 32-byte prehashed
+    /// message.
+    #[allow(non_snake_case)]
+    pub fn verify_prehashed(&self, msg: &[u8; 32], signature: &Signature) -> Result<(), Error> {
+        let P_x = &self.public_key_bytes;
+        let P = AffinePoint::decompress(P_x, Choice::from(0));
+
+        if P.is_none().into() {
+            return Err(Error::new());
+        }
+
+        let P = ProjectivePoint::from(P.unwrap());
+        let R_x = signature.r();
+        let s = signature.s();
+
+        let e_hash = tagged_hash(b"BIP0340/challenge", &[&R_x, P_x, msg]);
+        let e = Scalar::from_bytes_reduced(&e_hash);
+
+        let R_prime = (&ProjectivePoint::generator() * &s) + &(&P * &(-e));
+        let R_prime = R_prime.to_affine();
+
+        if R_prime.is_none().into() {
+            return Err(Error::new());
+        }
+
+        let R_prime = R_prime.unwrap();
+
+        if bool::from(R_prime.y.normalize().is_odd()) {
+            return Err(Error::new());
+        }
+
+        if bool::from(R_prime.x.to_bytes().ct_eq(&R_x)) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// BIP-340 Schnorr signature: 64 bytes encoding `(R.x, s)`.
+#[derive(Copy, Clone)]
+pub struct Signature {
+    bytes: [u8; SIZE],
+}
+
+impl Signature {
+    /// Parse a [`Signature`] from its 64-byte encoding.
+    ///
+    /// BIP-340 requires `0 <= R_x < p` and `0 <= s < n`; rejecting
+    /// out-of-range values here (rather than silently reducing them, as
+    /// `Scalar::from_bytes_reduced` would) is required to avoid
+    /// malleability: without this check, `(R_x, s)` and `(R_x, s + n)`
+    /// would both verify as the same signature.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: [u8; SIZE] = bytes.try_into().map_err(|_| Error::new())?;
+        let signature = Self { bytes };
+
+        if signature.r_is_in_range() && signature.s_checked().is_some() {
+            Ok(signature)
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// The `R.x` half of this signature.
+    fn r(&self) -> ElementBytes {
+        ElementBytes::clone_from_slice(&self.bytes[..32])
+    }
+
+    /// Is `R.x` a valid (i.e. in-range and on-curve) field element?
+    fn r_is_in_range(&self) -> bool {
+        AffinePoint::decompress(&self.r(), Choice::from(0))
+            .is_some()
+            .into()
+    }
+
+    /// The `s` half of this signature as a [`Scalar`], rejecting `s >= n`.
+    fn s_checked(&self) -> Option<Scalar> {
+        let s = Scalar::from_bytes(&ElementBytes::clone_from_slice(&self.bytes[32..]));
+        Option::from(s)
+    }
+
+    /// The `s` half of this signature, parsed into a [`Scalar`].
+    ///
+    /// Panics if `s` is out of range; only safe to call on a [`Signature`]
+    /// which was parsed via [`Signature::from_bytes`].
+    fn s(&self) -> Scalar {
+        self.s_checked()
+            .expect("signature `s` value out of range (should have been rejected by from_bytes)")
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Signature, SigningKey, VerifyingKey};
+    use crate::{NonZeroScalar, SecretKey};
+    use elliptic_curve::FromBytes;
+    use hex_literal::hex;
+
+    fn signing_key(sk_bytes: [u8; 32]) -> SigningKey {
+        let scalar = NonZeroScalar::from_bytes(&sk_bytes.into()).unwrap();
+        SigningKey::new(&SecretKey::from(scalar)).unwrap()
+    }
+
+    /// A subset of the official BIP-340 test vectors from
+    /// `bip-0340/test-vectors.csv`.
+    struct TestVector {
+        pk: [u8; 32],
+        msg: [u8; 32],
+        sig: [u8; 64],
+        valid: bool,
+    }
+
+    const VECTORS: &[TestVector] = &[
+        // Test vector 0
+        TestVector {
+            pk: hex!("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+            msg: hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            sig: hex!(
+                "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821
+                 525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0"
+            ),
+            valid: true,
+        },
+        // Test vector 1
+        TestVector {
+            pk: hex!("dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+            msg: hex!("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89"),
+            sig: hex!(
+                "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de334
+                 18906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a"
+            ),
+            valid: true,
+        },
+        // Test vector 2
+        TestVector {
+            pk: hex!("dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8"),
+            msg: hex!("7e2d58d8b3bcdf1abadec7829054f90dda9805aab56c77333024b9d0a508b75c"),
+            sig: hex!(
+                "5831aaeed7b44bb74e5eab94ba9d4294c49bcf2a60728d8b4c200f50dd313c1
+                 bab745879a5ad954a72c45a91c3a51d3c7adea98d82f8481e0e1e03674a6f3fb7"
+            ),
+            valid: true,
+        },
+        // Constructed vector: public key is not a valid x-coordinate on the
+        // curve (the smallest `x >= 2` for which no `y` exists), so it must
+        // be rejected whether or not the signature itself is well-formed.
+        TestVector {
+            pk: hex!("0000000000000000000000000000000000000000000000000000000000000005"),
+            msg: hex!("4242424242424242424242424242424242424242424242424242424242424242"),
+            sig: hex!(
+                "516396978cd1a9e55933b9516206359d6f022b17b0e59b26aac070e28fb21a76
+                 f87a43f5062cb2ef96dd18daa2c2f00e1f60c94d673f96c7bd201d68d01d8c4b"
+            ),
+            valid: false,
+        },
+        // Constructed vector: a valid `(pk, msg)` pair whose signature has
+        // `s` set to exactly the group order `n` (`s >= n` must be
+        // rejected).
+        TestVector {
+            pk: hex!("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            msg: hex!("4242424242424242424242424242424242424242424242424242424242424242"),
+            sig: hex!(
+                "516396978cd1a9e55933b9516206359d6f022b17b0e59b26aac070e28fb21a76
+                 fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+            ),
+            valid: false,
+        },
+        // Constructed vector: same `(pk, msg)`, but `R.x` set to exactly
+        // the field size `p` (`R.x >= p` must be rejected).
+        TestVector {
+            pk: hex!("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"),
+            msg: hex!("4242424242424242424242424242424242424242424242424242424242424242"),
+            sig: hex!(
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
+                 f87a43f5062cb2ef96dd18daa2c2f00e1f60c94d673f96c7bd201d68d01d8c4b"
+            ),
+            valid: false,
+        },
+    ];
+
+    #[test]
+    fn official_test_vectors() {
+        for vector in VECTORS {
+            let result = match (
+                VerifyingKey::from_bytes(&vector.pk),
+                Signature::from_bytes(&vector.sig),
+            ) {
+                (Ok(pk), Ok(sig)) => pk.verify_prehashed(&vector.msg, &sig).is_ok(),
+                _ => false,
+            };
+
+            assert_eq!(result, vector.valid);
+        }
+    }
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let signing_key = signing_key(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        ));
+        let verifying_key = signing_key.verifying_key();
+
+        let msg = [0x42; 32];
+        let aux_rand = [0x24; 32];
+        let signature = signing_key.try_sign_prehashed(&msg, &aux_rand).unwrap();
+
+        assert!(verifying_key.verify_prehashed(&msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_and_verify_round_trip_with_odd_y_public_key() {
+        // `sk = 6` is the smallest scalar whose public point `sk * G` has an
+        // odd y-coordinate, so constructing its `SigningKey` exercises the
+        // secret-key negation branch of `lift_x` that `sk = 1` (even y)
+        // above does not.
+        let signing_key = signing_key(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000006"
+        ));
+        let verifying_key = signing_key.verifying_key();
+
+        let msg = [0x43; 32];
+        let aux_rand = [0x25; 32];
+        let signature = signing_key.try_sign_prehashed(&msg, &aux_rand).unwrap();
+
+        assert!(verifying_key.verify_prehashed(&msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_s_greater_than_or_equal_to_order() {
+        let msg = hex!("4242424242424242424242424242424242424242424242424242424242424242");
+        let sig = hex!(
+            "516396978cd1a9e55933b9516206359d6f022b17b0e59b26aac070e28fb21a76
+             f87a43f5062cb2ef96dd18daa2c2f00e1f60c94d673f96c7bd201d68d01d8c4b"
+        );
+
+        // Sanity check: the original signature is valid.
+        let pk = VerifyingKey::from_bytes(&hex!(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        ))
+        .unwrap();
+        assert!(pk
+            .verify_prehashed(&msg, &Signature::from_bytes(&sig).unwrap())
+            .is_ok());
+
+        // Malleate `s` by replacing it with the secp256k1 group order `n`
+        // itself (`s >= n` must be rejected rather than silently reduced
+        // to `0`, which `Scalar::from_bytes_reduced` would otherwise do).
+        let mut malleated = sig;
+        malleated[32..].copy_from_slice(&hex!(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+        ));
+
+        assert!(Signature::from_bytes(&malleated).is_err());
+    }
+}